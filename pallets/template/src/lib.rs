@@ -1,10 +1,59 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-    decl_module, decl_storage, decl_event, decl_error, ensure, StorageMap
+    decl_module, decl_storage, decl_event, decl_error, ensure, debug, dispatch, StorageMap, StorageValue,
+    traits::{Get, Currency, ReservableCurrency},
 };
-use frame_system::ensure_signed;
-use sp_std::vec::Vec;
+use frame_system::{
+    ensure_signed,
+    offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::offchain::{http, storage::StorageValueRef, Duration};
+use sp_runtime::traits::SaturatedConversion;
+use sp_std::{vec::Vec, str};
+
+/// Currency 余额类型的便捷别名。
+type BalanceOf<T> =
+    <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+
+/// 链下工作者签名交易所用的密钥类型标识。
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"poe!");
+
+/// 公证服务的 HTTP 端点。部署时可按需替换为实际的第三方时间戳服务地址。
+const NOTARY_ENDPOINT: &str = "http://localhost:3000/notarize";
+
+/// 链下"在途"标记的宽限期（区块数）：已提交的公证交易在此期间内
+/// 不再重复提交，超过后若仍未上链才会重试，避免灌爆交易池。
+const NOTARIZE_GRACE_PERIOD: u32 = 5;
+
+/// 基于 sr25519 的应用级密钥定义，供链下工作者对 `submit_notarization` 签名。
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_runtime::app_crypto::{app_crypto, sr25519};
+    use sp_runtime::traits::Verify;
+    use sp_runtime::{MultiSignature, MultiSigner};
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// 链下工作者使用的身份标识。
+    pub struct AuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+
+    impl frame_system::offchain::AppCrypto<
+        <sr25519::Signature as Verify>::Signer,
+        sr25519::Signature,
+    > for AuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
 
 #[cfg(test)]
 mod mock;
@@ -14,10 +63,25 @@ mod tests;
 
 /// Configure the pallet by specifying the parameters and types on which it depends.
 /// 通过指定托盘所依赖的参数和类型来配置托盘。
-pub trait Trait: frame_system::Trait {
+pub trait Trait: frame_system::Trait + CreateSignedTransaction<Call<Self>> {
 	/// Because this pallet emits events, it depends on the runtime's definition of an event.
 	/// 因为此托盘会发出事件，所以它依赖于运行时对事件的定义。
 	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// 存证内容的最大长度，用来限制单个存证占用的链上存储空间。
+	type MaxClaimLength: Get<u32>;
+
+	/// 用于质押存证押金的货币系统。
+	type Currency: ReservableCurrency<Self::AccountId>;
+
+	/// 创建存证时需要质押的押金金额，撤销时原额退还。
+	type ClaimDeposit: Get<BalanceOf<Self>>;
+
+	/// 链下工作者提交公证结果所用的签名密钥。
+	type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+	/// 本模块的 Call 类型，供链下工作者构造签名交易。
+	type Call: From<Call<Self>>;
 }
 
 // The pallet's runtime storage items.
@@ -27,8 +91,17 @@ pub trait Trait: frame_system::Trait {
 decl_storage! {
     trait Store for Module<T: Trait> as TemplateModule {
         /// The storage item for our proofs.
-        /// It maps a proof to the user who made the claim and when they made it.
-        Proofs: map hasher(blake2_128_concat) Vec<u8> => (T::AccountId, T::BlockNumber);
+        /// It maps a proof to the user who made the claim, when they made it,
+        /// an optional expiry block after which the claim is purged,
+        /// the reserved deposit, and an optional external notarization timestamp.
+        Proofs get(fn proofs): map hasher(blake2_128_concat) Vec<u8> => (T::AccountId, T::BlockNumber, Option<T::BlockNumber>, BalanceOf<T>, Option<u64>);
+
+        /// 到期索引：按到期区块号分组，记录该区块应被清理的存证列表。
+        /// on_finalize 在区块结束时消费该索引，实现存证的自动过期。
+        ExpiringAt: map hasher(blake2_128_concat) T::BlockNumber => Vec<Vec<u8>>;
+
+        /// 尚未经过外部公证的存证集合，由链下工作者扫描并逐个公证。
+        UnnotarizedProofs: Vec<Vec<u8>>;
     }
 }
 
@@ -36,13 +109,22 @@ decl_storage! {
 // Event documentation should end with an array that provides descriptive names for parameters.
 // https://substrate.dev/docs/en/knowledgebase/runtime/events
 decl_event! {
-    pub enum Event<T> where AccountId = <T as frame_system::Trait>::AccountId {
-        /// Event emitted when a proof has been claimed. [who, claim]
-        ClaimCreated(AccountId, Vec<u8>),
-        /// Event emitted when a claim is revoked by the owner. [who, claim]
-        ClaimRevoked(AccountId, Vec<u8>),
-        /// Event emitted when a claim is changed by the owner. [who, to, claim]  ///simon
-        ClaimChanged(AccountId, Receiver, Vec<u8>),
+    pub enum Event<T> where
+        AccountId = <T as frame_system::Trait>::AccountId,
+        Balance = BalanceOf<T>,
+    {
+        /// Event emitted when a proof has been claimed. [who, claim, deposit]
+        ClaimCreated(AccountId, Vec<u8>, Balance),
+        /// Event emitted when a claim is revoked by the owner. [who, claim, deposit]
+        ClaimRevoked(AccountId, Vec<u8>, Balance),
+        /// Event emitted when a claim is changed by the owner. [who, to, claim, deposit]  ///simon
+        ClaimChanged(AccountId, AccountId, Vec<u8>, Balance),
+        /// Event emitted when a claim is purged because it reached its expiry block. [claim]
+        ClaimExpired(Vec<u8>),
+        /// Event emitted when a claim has been notarized with an external timestamp. [claim, external_ts]
+        ClaimNotarized(Vec<u8>, u64),
+        /// Event emitted when a claim's owner has been verified on-chain. [who, claim]
+        ClaimVerified(AccountId, Vec<u8>),
     }
 }
 
@@ -55,6 +137,12 @@ decl_error! {
         NoSuchProof,
         /// The proof is claimed by another account, so caller can't revoke it.
         NotProofOwner,
+        /// The proof is too long, it exceeds `MaxClaimLength`.
+        ProofTooLong,
+        /// The account cannot cover the required claim deposit.
+        InsufficientBalance,
+        /// No local account was available to sign the notarization transaction.
+        NoLocalAcctForSigning,
     }
 }
 
@@ -73,69 +161,164 @@ decl_module! {
         // 如果在可调用函数里，需要触发事件，就需要这样写，可以理解成固定用法
         fn deposit_event() = default;
 
+        // offchain_worker 在每个区块开头、链外执行，不占用链上计算与存储资源。
+        // 这里用它把待公证的存证提交给外部时间戳服务，再把结果签名回链。
+        fn offchain_worker(block: T::BlockNumber) {
+            if let Err(e) = Self::notarize_one(block) {
+                debug::error!("offchain notarization failed: {:?}", e);
+            }
+        }
+
+        // on_finalize 在每个区块结束时执行：清理在本区块到期的存证。
+        fn on_finalize(n: T::BlockNumber) {
+            for proof in ExpiringAt::<T>::take(n) {
+                // 仅当存证依然存在且其到期区块确实是 n 时才清理，
+                // 避免 change_owner/revoke 之后留下的悬空索引误删新存证。
+                if !Proofs::<T>::contains_key(&proof) {
+                    continue;
+                }
+                let (owner, _, expiry, deposit, _) = Proofs::<T>::get(&proof);
+                if expiry == Some(n) {
+                    // 过期同样把押金退还给所有者，与 revoke 保持一致。
+                    T::Currency::unreserve(&owner, deposit);
+                    Proofs::<T>::remove(&proof);
+                    Self::remove_from_unnotarized(&proof);
+                    Self::deposit_event(RawEvent::ClaimExpired(proof));
+                }
+            }
+        }
+
         /// 允许用户提交一个未存证的存证
         /// weight是当前函数的权重
         #[weight = 10_000]
-        fn create_claim(origin, proof: Vec<u8>) {
+        fn create_claim(origin, proof: Vec<u8>) -> dispatch::DispatchResult {
             // Check that the extrinsic was signed and get the signer.
             // This function will return an error if the extrinsic is not signed.
             // https://substrate.dev/docs/en/knowledgebase/runtime/origin
             let sender = ensure_signed(origin)?;
 
+            // 限制存证长度，避免单个交易写入过大的数据而膨胀链上状态。
+            ensure!(proof.len() as u32 <= T::MaxClaimLength::get(), Error::<T>::ProofTooLong);
+
             // Verify that the specified proof has not already been claimed.
             ensure!(!Proofs::<T>::contains_key(&proof), Error::<T>::ProofAlreadyClaimed);
 
+            // 质押押金，余额不足时返回 InsufficientBalance。
+            let deposit = T::ClaimDeposit::get();
+            T::Currency::reserve(&sender, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+
             // Get the block number from the FRAME System module.
             let current_block = <frame_system::Module<T>>::block_number();
 
-            // Store the proof with the sender and block number.
-            Proofs::<T>::insert(&proof, (&sender, current_block));
+            // Store the proof with the sender and block number. A plain claim never expires.
+            Proofs::<T>::insert(&proof, (&sender, current_block, None::<T::BlockNumber>, deposit, None::<u64>));
+
+            // 登记为待公证，链下工作者会在后续区块异步地为其获取外部时间戳。
+            UnnotarizedProofs::append(proof.clone());
 
             // Emit an event that the claim was created.
-            Self::deposit_event(RawEvent::ClaimCreated(sender, proof));
+            Self::deposit_event(RawEvent::ClaimCreated(sender, proof, deposit));
+
+            Ok(())
+        }
+
+        /// 允许用户提交一个会在 `duration` 个区块后自动过期的存证。
+        /// 过期时由 on_finalize 统一清理，并触发 ClaimExpired 事件。
+        #[weight = 10_000]
+        fn create_claim_with_expiry(origin, proof: Vec<u8>, duration: T::BlockNumber) {
+            let sender = ensure_signed(origin)?;
+
+            // 限制存证长度，避免单个交易写入过大的数据而膨胀链上状态。
+            ensure!(proof.len() as u32 <= T::MaxClaimLength::get(), Error::<T>::ProofTooLong);
+
+            // Verify that the specified proof has not already been claimed.
+            ensure!(!Proofs::<T>::contains_key(&proof), Error::<T>::ProofAlreadyClaimed);
+
+            // 质押押金，余额不足时返回 InsufficientBalance。
+            let deposit = T::ClaimDeposit::get();
+            T::Currency::reserve(&sender, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            let current_block = <frame_system::Module<T>>::block_number();
+            let expires_at = current_block + duration;
+
+            // 存储存证，并记录其到期区块。
+            Proofs::<T>::insert(&proof, (&sender, current_block, Some(expires_at), deposit, None::<u64>));
+
+            // 在到期索引中登记该存证，供 on_finalize 清理。
+            ExpiringAt::<T>::append(expires_at, proof.clone());
+
+            // 登记为待公证，链下工作者会在后续区块异步地为其获取外部时间戳。
+            UnnotarizedProofs::append(proof.clone());
+
+            Self::deposit_event(RawEvent::ClaimCreated(sender, proof, deposit));
         }
 
         /// Allow the owner to revoke their claim.
         #[weight = 10_000]
-        fn revoke_claim(origin, proof: Vec<u8>) {
+        fn revoke_claim(origin, proof: Vec<u8>) -> dispatch::DispatchResult {
             // Check that the extrinsic was signed and get the signer.
             // This function will return an error if the extrinsic is not signed.
             // https://substrate.dev/docs/en/knowledgebase/runtime/origin
             let sender = ensure_signed(origin)?;
 
+            // 限制存证长度，与 create_claim 保持一致，避免超长 key 的查找开销。
+            ensure!(proof.len() as u32 <= T::MaxClaimLength::get(), Error::<T>::ProofTooLong);
+
             // Verify that the specified proof has been claimed.
             ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
 
             // Get owner of the claim.
-            let (owner, _) = Proofs::<T>::get(&proof);
+            let (owner, _, expiry, deposit, _) = Proofs::<T>::get(&proof);
 
             // Verify that sender of the current call is the claim owner.
             ensure!(sender == owner, Error::<T>::NotProofOwner);
 
+            // 如果该存证登记了到期索引，先清理索引，避免留下悬空项。
+            Self::remove_from_expiring(&proof, expiry);
+
+            // 若尚未公证，也要从待公证集合中移除，避免悬空项。
+            Self::remove_from_unnotarized(&proof);
+
+            // 撤销时把押金原额退还给当前所有者。
+            T::Currency::unreserve(&owner, deposit);
+
             // Remove claim from storage.
             Proofs::<T>::remove(&proof);
 
             // Emit an event that the claim was erased.
-            Self::deposit_event(RawEvent::ClaimRevoked(sender, proof));
+            Self::deposit_event(RawEvent::ClaimRevoked(sender, proof, deposit));
+
+            Ok(())
         }
 
         /// 允许转移存证给他人
         #[weight = 10_000]
-        fn change_owner_claim(origin, receiver: T::AccountId, proof: Vec<u8>) {
+        fn change_owner_claim(origin, receiver: T::AccountId, proof: Vec<u8>) -> dispatch::DispatchResult {
             // 检查调用者是否已签名
             // 如果未签名，则函数将返回错误
             // https://substrate.dev/docs/en/knowledgebase/runtime/origin
             let sender = ensure_signed(origin)?;
 
+            // 限制存证长度，避免单个交易写入过大的数据而膨胀链上状态。
+            ensure!(proof.len() as u32 <= T::MaxClaimLength::get(), Error::<T>::ProofTooLong);
+
             // 检查存证是否存在
             ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
 
             // 获取存证的所有者
-            let (owner, _) = Proofs::<T>::get(&proof);
+            let (owner, _, expiry, deposit, external_ts) = Proofs::<T>::get(&proof);
 
             // 检查调用者是否为存证的所有者
             ensure!(sender == owner, Error::<T>::NotProofOwner);
 
+            // 转移押金义务：先从接收方质押，成功后再解押发送方，
+            // 若接收方余额不足则整笔调用失败，发送方的押金保持不变。
+            T::Currency::reserve(&receiver, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            T::Currency::unreserve(&sender, deposit);
+
             // 从FRAME系统模块获取块号
             let current_block = <frame_system::Module<T>>::block_number();
 
@@ -143,10 +326,11 @@ decl_module! {
             // 都是用insert，因为存储时是用proof作为key，修改时直接覆盖这个key的值即可
             // 参考：https://substrate.dev/rustdocs/v2.0.0/frame_support/storage/trait.StorageMap.html
             // https://substrate.dev/recipes/storage-maps.html
-            Proofs::<T>::insert(&proof, (&receiver, current_block));
+            // 转移时保留既有的到期区块与外部公证时间戳，避免把临时存证悄悄变成永久存证。
+            Proofs::<T>::insert(&proof, (&receiver, current_block, expiry, deposit, external_ts));
 
             // 触发修改存证所有者事件
-            Self::deposit_event(RawEvent::ClaimChanged(sender, receiver, proof));
+            Self::deposit_event(RawEvent::ClaimChanged(sender, receiver, proof, deposit));
 
             // Runtime模块里存在保留函数，除了deposit_event之外，还有：
             // on_initialize，在每个区块的开头执行；
@@ -155,7 +339,184 @@ decl_module! {
             // 用来执行一些计算复杂度高，或者需要与外部的数据源进行交互的场景，
             // 比如当我们需要http请求外部数据时，就需要用到offchain_worker，优势是不占用链上的计算和存储资源
             // on_runtime_upgrade，当有runtime升级时才会执行，用来迁移数据。
+
+            Ok(())
+        }
+
+        /// 只读校验：当存证存在且其所有者与 `expected_owner` 一致时成功，
+        /// 并触发 ClaimVerified 事件；否则返回 NoSuchProof 或 NotProofOwner。
+        /// 为链下验证工具提供一条可直接调用的链上校验路径。
+        #[weight = 1_000]
+        fn verify_claim(origin, proof: Vec<u8>, expected_owner: T::AccountId) -> dispatch::DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            // 限制存证长度，与 create_claim 保持一致。
+            ensure!(proof.len() as u32 <= T::MaxClaimLength::get(), Error::<T>::ProofTooLong);
+
+            // 存证必须存在才能校验。
+            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+
+            // 比对存储中的所有者与调用者期望的所有者。
+            let (owner, _, _, _, _) = Proofs::<T>::get(&proof);
+            ensure!(owner == expected_owner, Error::<T>::NotProofOwner);
+
+            // 事件汇报被校验的存证所有者本身，而非调用者，
+            // 这样同一存证无论谁来校验都会产生一致的事件。
+            Self::deposit_event(RawEvent::ClaimVerified(owner, proof));
+
+            Ok(())
+        }
+
+        /// 由链下工作者签名回链的调用，记录外部公证服务返回的时间戳。
+        /// 在函数内部把存证从 `UnnotarizedProofs` 移除，以防重复提交。
+        #[weight = 10_000]
+        fn submit_notarization(origin, proof: Vec<u8>, external_ts: u64) {
+            // 必须是签名交易，由链下工作者用配置的密钥签署。
+            let _who = ensure_signed(origin)?;
+
+            // 限制存证长度，与 create_claim 保持一致。
+            ensure!(proof.len() as u32 <= T::MaxClaimLength::get(), Error::<T>::ProofTooLong);
+
+            // 存证可能在公证期间已被撤销，此时以 NoSuchProof 拒绝本次调用。
+            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+
+            // 防重复：无论本次是否更新，都把它移出待公证集合。
+            Self::remove_from_unnotarized(&proof);
+
+            Proofs::<T>::mutate(&proof, |entry| {
+                entry.4 = Some(external_ts);
+            });
+
+            Self::deposit_event(RawEvent::ClaimNotarized(proof, external_ts));
         }
 
     }
 }
+
+impl<T: Trait> Module<T> {
+    /// 从到期索引 `ExpiringAt` 中移除某个存证。
+    /// 当存证被撤销或转移、不再按原计划过期时调用，避免索引悬空。
+    fn remove_from_expiring(proof: &[u8], expiry: Option<T::BlockNumber>) {
+        if let Some(expires_at) = expiry {
+            ExpiringAt::<T>::mutate(expires_at, |proofs| {
+                proofs.retain(|p| p.as_slice() != proof);
+            });
+        }
+    }
+
+    /// 从待公证集合 `UnnotarizedProofs` 中移除某个存证。
+    fn remove_from_unnotarized(proof: &[u8]) {
+        UnnotarizedProofs::mutate(|proofs| {
+            proofs.retain(|p| p.as_slice() != proof);
+        });
+    }
+
+    /// 每个区块至多公证一个存证，避免链下执行时间过长。
+    /// 向外部公证服务发起 HTTP 请求获取时间戳，再签名回链。
+    fn notarize_one(block: T::BlockNumber) -> Result<(), &'static str> {
+        // 限速：每个区块只处理队首的一个存证。
+        let proof = match UnnotarizedProofs::get().into_iter().next() {
+            Some(proof) => proof,
+            None => return Ok(()),
+        };
+
+        // 链下"在途"标记：同一存证的签名交易在被打包上链之前，
+        // 不在后续区块重复提交，避免向交易池灌入大量重复签名交易。
+        // 交易最终上链后，submit_notarization 会把它移出队列，队首自然前移。
+        let mut lock_key = b"poe::notarize-inflight::".to_vec();
+        lock_key.extend_from_slice(&proof);
+        let marker = StorageValueRef::persistent(&lock_key);
+        let grace: T::BlockNumber = NOTARIZE_GRACE_PERIOD.saturated_into();
+        let guarded = marker.mutate(|last: Option<Option<T::BlockNumber>>| {
+            match last {
+                // 上一次提交仍在宽限期内，拒绝本次提交。
+                Some(Some(submitted_at)) if block < submitted_at + grace =>
+                    Err("notarization already in flight"),
+                // 从未提交或宽限期已过，记录本次提交的区块号。
+                _ => Ok(block),
+            }
+        });
+        match guarded {
+            // 成功取得标记，继续提交。
+            Ok(Ok(_)) => {}
+            // 取标记时发生并发写入竞争，下个区块再试。
+            Ok(Err(_)) => return Ok(()),
+            // 仍在在途宽限期内，正常跳过本区块。
+            Err(_) => return Ok(()),
+        }
+
+        let external_ts = Self::fetch_external_ts(&proof)?;
+        Self::submit_notarization_signed(proof, external_ts)
+    }
+
+    /// 向公证端点发起 GET 请求，并从返回的 JSON body 中解析外部时间戳。
+    fn fetch_external_ts(proof: &[u8]) -> Result<u64, &'static str> {
+        // 把存证内容以十六进制附加到 URL 上作为查询参数。
+        let mut url = Vec::new();
+        url.extend_from_slice(NOTARY_ENDPOINT.as_bytes());
+        url.extend_from_slice(b"?proof=");
+        for byte in proof {
+            url.extend_from_slice(&Self::hex_byte(*byte));
+        }
+        let url = str::from_utf8(&url).map_err(|_| "invalid utf8 url")?;
+
+        let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(3_000));
+        let request = http::Request::get(url);
+        let pending = request
+            .deadline(deadline)
+            .send()
+            .map_err(|_| "http request failed")?;
+        let response = pending
+            .try_wait(deadline)
+            .map_err(|_| "http request timed out")?
+            .map_err(|_| "http request errored")?;
+
+        if response.code != 200 {
+            return Err("unexpected http status code");
+        }
+
+        let body = response.body().collect::<Vec<u8>>();
+        Self::parse_timestamp(&body)
+    }
+
+    /// 从形如 `{"timestamp":1690000000}` 的 JSON body 中提取整数时间戳。
+    fn parse_timestamp(body: &[u8]) -> Result<u64, &'static str> {
+        let body = str::from_utf8(body).map_err(|_| "invalid utf8 body")?;
+        let key = "\"timestamp\"";
+        let start = body.find(key).ok_or("timestamp field not found")?;
+        let rest = &body[start + key.len()..];
+        let digits: Vec<u8> = rest
+            .bytes()
+            .skip_while(|b| !b.is_ascii_digit())
+            .take_while(|b| b.is_ascii_digit())
+            .collect();
+        if digits.is_empty() {
+            return Err("timestamp value not found");
+        }
+        let digits = str::from_utf8(&digits).map_err(|_| "invalid timestamp digits")?;
+        digits.parse::<u64>().map_err(|_| "timestamp parse error")
+    }
+
+    /// 用链下工作者配置的密钥签名并提交 `submit_notarization`。
+    fn submit_notarization_signed(proof: Vec<u8>, external_ts: u64) -> Result<(), &'static str> {
+        let signer = Signer::<T, T::AuthorityId>::all_accounts();
+        if !signer.can_sign() {
+            return Err("no local account available for signing");
+        }
+        let results = signer.send_signed_transaction(|_acct| {
+            Call::submit_notarization(proof.clone(), external_ts)
+        });
+        for (_acct, res) in &results {
+            if res.is_err() {
+                return Err("failed to submit signed notarization");
+            }
+        }
+        Ok(())
+    }
+
+    /// 把单个字节格式化为两位小写十六进制字符串。
+    fn hex_byte(byte: u8) -> sp_std::vec::Vec<u8> {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        sp_std::vec![HEX[(byte >> 4) as usize], HEX[(byte & 0x0f) as usize]]
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{Error, Proofs, ExpiringAt, UnnotarizedProofs, mock::*};
+use frame_support::{assert_ok, assert_noop, traits::OnFinalize};
+
+#[test]
+fn create_claim_works() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), proof.clone()));
+
+		assert_eq!(
+			Proofs::<Test>::get(&proof),
+			(1, frame_system::Module::<Test>::block_number(), None, 10, None)
+		);
+	});
+}
+
+#[test]
+fn create_claim_failed_when_claim_already_exist() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		let _ = PoeModule::create_claim(Origin::signed(1), proof.clone());
+
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), proof.clone()),
+			Error::<Test>::ProofAlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn create_claim_failed_when_proof_too_long() {
+	new_test_ext().execute_with(|| {
+		// `MaxClaimLength` is 6 in the mock runtime.
+		let proof = vec![0, 1, 2, 3, 4, 5, 6];
+
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), proof.clone()),
+			Error::<Test>::ProofTooLong
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_works() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		let _ = PoeModule::create_claim(Origin::signed(1), proof.clone());
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), proof.clone()));
+		assert_eq!(Proofs::<Test>::contains_key(&proof), false);
+	});
+}
+
+#[test]
+fn revoke_claim_failed_when_proof_not_exist() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), proof.clone()),
+			Error::<Test>::NoSuchProof
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_failed_when_not_proof_owner() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		let _ = PoeModule::create_claim(Origin::signed(1), proof.clone());
+
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(2), proof.clone()),
+			Error::<Test>::NotProofOwner
+		);
+	});
+}
+
+#[test]
+fn change_owner_claim_works() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		let _ = PoeModule::create_claim(Origin::signed(1), proof.clone());
+
+		assert_ok!(PoeModule::change_owner_claim(Origin::signed(1), 2, proof.clone()));
+		assert_eq!(
+			Proofs::<Test>::get(&proof),
+			(2, frame_system::Module::<Test>::block_number(), None, 10, None)
+		);
+	});
+}
+
+#[test]
+fn claim_with_expiry_is_purged_on_finalize() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		let current = frame_system::Module::<Test>::block_number();
+		assert_ok!(PoeModule::create_claim_with_expiry(Origin::signed(1), proof.clone(), 5));
+
+		let expires_at = current + 5;
+		assert_eq!(ExpiringAt::<Test>::get(expires_at), vec![proof.clone()]);
+		assert_eq!(Proofs::<Test>::get(&proof), (1, current, Some(expires_at), 10, None));
+
+		// 到期区块结束时应被清理。
+		PoeModule::on_finalize(expires_at);
+		assert_eq!(Proofs::<Test>::contains_key(&proof), false);
+		assert_eq!(ExpiringAt::<Test>::contains_key(expires_at), false);
+	});
+}
+
+#[test]
+fn revoke_claim_cleans_up_expiring_index() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		let current = frame_system::Module::<Test>::block_number();
+		assert_ok!(PoeModule::create_claim_with_expiry(Origin::signed(1), proof.clone(), 5));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), proof.clone()));
+		assert_eq!(ExpiringAt::<Test>::get(current + 5), Vec::<Vec<u8>>::new());
+	});
+}
+
+#[test]
+fn create_claim_reserves_deposit_and_revoke_returns_it() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), proof.clone()));
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), proof.clone()));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn change_owner_transfers_the_deposit_obligation() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), proof.clone()));
+
+		assert_ok!(PoeModule::change_owner_claim(Origin::signed(1), 2, proof.clone()));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 10);
+	});
+}
+
+#[test]
+fn create_claim_failed_when_balance_insufficient() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		// Account 3 has no balance in the mock genesis.
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(3), proof.clone()),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn create_claim_appends_to_unnotarized_queue() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), proof.clone()));
+		assert_eq!(UnnotarizedProofs::get(), vec![proof]);
+	});
+}
+
+#[test]
+fn submit_notarization_records_ts_and_dequeues() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), proof.clone()));
+
+		assert_ok!(PoeModule::submit_notarization(Origin::signed(1), proof.clone(), 1690000000));
+
+		// 外部时间戳被写入存证的第 5 个字段。
+		let (_, _, _, _, external_ts) = Proofs::<Test>::get(&proof);
+		assert_eq!(external_ts, Some(1690000000));
+
+		// 已公证的存证从待公证队列移除，防止重复提交。
+		assert_eq!(UnnotarizedProofs::get(), Vec::<Vec<u8>>::new());
+	});
+}
+
+#[test]
+fn submit_notarization_failed_when_proof_not_exist() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+
+		assert_noop!(
+			PoeModule::submit_notarization(Origin::signed(1), proof.clone(), 1690000000),
+			Error::<Test>::NoSuchProof
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_removes_from_unnotarized_queue() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), proof.clone()));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), proof.clone()));
+		assert_eq!(UnnotarizedProofs::get(), Vec::<Vec<u8>>::new());
+	});
+}
+
+#[test]
+fn parse_timestamp_reads_valid_body() {
+	assert_eq!(
+		PoeModule::parse_timestamp(br#"{"timestamp":1690000000}"#),
+		Ok(1690000000)
+	);
+}
+
+#[test]
+fn parse_timestamp_fails_on_missing_field() {
+	assert!(PoeModule::parse_timestamp(br#"{"other":1}"#).is_err());
+}
+
+#[test]
+fn parse_timestamp_fails_on_non_numeric() {
+	assert!(PoeModule::parse_timestamp(br#"{"timestamp":"soon"}"#).is_err());
+}
+
+#[test]
+fn verify_claim_works_for_correct_owner() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		let _ = PoeModule::create_claim(Origin::signed(1), proof.clone());
+
+		assert_ok!(PoeModule::verify_claim(Origin::signed(2), proof.clone(), 1));
+	});
+}
+
+#[test]
+fn verify_claim_failed_when_owner_mismatch() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+		let _ = PoeModule::create_claim(Origin::signed(1), proof.clone());
+
+		assert_noop!(
+			PoeModule::verify_claim(Origin::signed(2), proof.clone(), 2),
+			Error::<Test>::NotProofOwner
+		);
+	});
+}
+
+#[test]
+fn verify_claim_failed_when_proof_not_exist() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1];
+
+		assert_noop!(
+			PoeModule::verify_claim(Origin::signed(1), proof.clone(), 1),
+			Error::<Test>::NoSuchProof
+		);
+	});
+}
+
+#[test]
+fn change_owner_claim_failed_when_proof_too_long() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![0, 1, 2, 3, 4, 5, 6];
+
+		assert_noop!(
+			PoeModule::change_owner_claim(Origin::signed(1), 2, proof.clone()),
+			Error::<Test>::ProofTooLong
+		);
+	});
+}